@@ -1,6 +1,7 @@
 use crate::config::ModuleConfig;
 use starship_module_config_derive::ModuleConfig;
 use serde::Serialize;
+use std::collections::HashMap;
 
 #[derive(Clone, ModuleConfig, Serialize)]
 pub struct AzureConfig<'a> {
@@ -8,6 +9,13 @@ pub struct AzureConfig<'a> {
     pub symbol: &'a str,
     pub style: &'a str,
     pub disabled: bool,
+    /// Maps a subscription id or name to the string that should be displayed
+    /// for it instead, e.g. `{ "00000000-0000-0000-0000-000000000000" = "prod" }`.
+    pub aliases: HashMap<String, &'a str>,
+    /// Truncates the subscription name to this many grapheme clusters when no
+    /// alias matches. `0` disables truncation.
+    pub truncation_length: i64,
+    pub truncation_symbol: &'a str,
 }
 
 impl<'a> Default for AzureConfig<'a> {
@@ -17,6 +25,9 @@ impl<'a> Default for AzureConfig<'a> {
             symbol: "ï´ƒ ",
             style: "blue bold",
             disabled: false,
+            aliases: HashMap::new(),
+            truncation_length: 0,
+            truncation_symbol: "…",
         }
     }
 }
@@ -1,7 +1,9 @@
+use encoding_rs::Encoding;
 use ini::Ini;
+use serde::{Deserialize, Serialize};
 use std::fs::File;
-use std::io::{BufReader, Read};
-use std::path::PathBuf;
+use std::io::{BufReader, Read, Write};
+use std::path::{Path, PathBuf};
 
 use super::{Context, Module, RootModuleConfig};
 
@@ -9,10 +11,33 @@ type JValue = serde_json::Value;
 
 use crate::configs::azure::AzureConfig;
 use crate::formatter::StringFormatter;
+use unicode_segmentation::UnicodeSegmentation;
 
 type SubscriptionId = String;
 type SubscriptionName = String;
 
+/// The fields of the active subscription entry in `azureProfile.json` that
+/// are surfaced as format variables.
+#[derive(Clone, Serialize, Deserialize)]
+struct SubscriptionInfo {
+    name: SubscriptionName,
+    username: Option<String>,
+    tenant: Option<String>,
+    cloud: Option<String>,
+}
+
+/// Sidecar cache of the resolved subscription info for `azureProfile.json`,
+/// keyed on the profile file's last-modified time and size so that an
+/// `az account set` (which rewrites the profile) is picked up immediately.
+#[derive(Serialize, Deserialize)]
+struct SubscriptionInfoCache {
+    mtime_secs: u64,
+    mtime_nanos: u32,
+    size: u64,
+    subscription_id: SubscriptionId,
+    info: SubscriptionInfo,
+}
+
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let mut module = context.new_module("azure");
     let config = AzureConfig::try_load(module.config);
@@ -22,7 +47,8 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     };
 
     let subscription_id = get_azure_subscription_id(context)?;
-    let subscription_name = get_azure_subscription_name(context, &subscription_id)?;
+    let subscription_info = get_azure_subscription_info(context, &subscription_id)?;
+    let subscription_display = resolve_subscription_display(&config, &subscription_id, &subscription_info.name);
 
     let parsed = StringFormatter::new(config.format).and_then(|formatter| {
         formatter
@@ -35,7 +61,10 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
                 _ => None,
             })
             .map(|variable| match variable {
-                "subscription" => Some(Ok(subscription_name.to_string())),
+                "subscription" => Some(Ok(subscription_display.clone())),
+                "username" => subscription_info.username.clone().map(Ok),
+                "tenant" => subscription_info.tenant.clone().map(Ok),
+                "cloud" => subscription_info.cloud.clone().map(Ok),
                 _ => None,
             })
             .parse(None)
@@ -75,18 +104,137 @@ fn get_azure_subscription_id(context: &Context) -> Option<SubscriptionId> {
     return Some(current_subscription_id.to_string());
   }
 
-fn get_azure_subscription_name(context: &Context, subscription_id: &SubscriptionId) -> Option<SubscriptionName> {
-    let mut config_path = get_config_file_location(context)?; 
-    config_path.push("azureProfile.json");
+fn get_azure_subscription_info(
+    context: &Context,
+    subscription_id: &SubscriptionId,
+) -> Option<SubscriptionInfo> {
+    let config_dir = get_config_file_location(context)?;
+    let mut profile_path = config_dir.clone();
+    profile_path.push("azureProfile.json");
+
+    let metadata = std::fs::metadata(&profile_path).ok()?;
+    let modified = metadata.modified().ok()?;
+    let since_epoch = modified
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default();
+
+    let mut cache_path = config_dir;
+    cache_path.push("starship_azure_cache.json");
+
+    if let Some(cached) = read_cached_subscription_info(
+        &cache_path,
+        since_epoch.as_secs(),
+        since_epoch.subsec_nanos(),
+        metadata.len(),
+        subscription_id,
+    ) {
+        return Some(cached);
+    }
 
-    if let Some(parsed_json) = parse_json(&config_path) {
-        let subscriptions = parsed_json["subscriptions"].as_array()?;
+    let parsed_json = parse_json(&profile_path)?;
+    let subscriptions = parsed_json["subscriptions"].as_array()?;
+    let info = subscriptions
+        .iter()
+        .find_map(|s| find_subscription(s, subscription_id.to_string()))?;
+
+    write_cached_subscription_info(
+        &cache_path,
+        since_epoch.as_secs(),
+        since_epoch.subsec_nanos(),
+        metadata.len(),
+        subscription_id,
+        &info,
+    );
+
+    Some(info)
+}
 
-        subscriptions
-            .iter()
-            .find_map(|s| find_subscription_name(s, subscription_id.to_string()))
+fn read_cached_subscription_info(
+    cache_path: &Path,
+    mtime_secs: u64,
+    mtime_nanos: u32,
+    size: u64,
+    subscription_id: &SubscriptionId,
+) -> Option<SubscriptionInfo> {
+    let cache_file = File::open(cache_path).ok()?;
+    let cache: SubscriptionInfoCache = serde_json::from_reader(BufReader::new(cache_file)).ok()?;
+
+    if cache.mtime_secs == mtime_secs
+        && cache.mtime_nanos == mtime_nanos
+        && cache.size == size
+        && cache.subscription_id == *subscription_id
+    {
+        Some(cache.info)
     } else {
-        return None;
+        None
+    }
+}
+
+fn write_cached_subscription_info(
+    cache_path: &Path,
+    mtime_secs: u64,
+    mtime_nanos: u32,
+    size: u64,
+    subscription_id: &SubscriptionId,
+    info: &SubscriptionInfo,
+) {
+    let cache = SubscriptionInfoCache {
+        mtime_secs,
+        mtime_nanos,
+        size,
+        subscription_id: subscription_id.to_string(),
+        info: info.clone(),
+    };
+
+    let contents = match serde_json::to_string(&cache) {
+        Ok(contents) => contents,
+        Err(error) => {
+            log::warn!("Error serializing azure subscription cache:\n{}", error);
+            return;
+        }
+    };
+
+    if let Err(error) =
+        File::create(cache_path).and_then(|mut file| file.write_all(contents.as_bytes()))
+    {
+        log::warn!("Error writing azure subscription cache:\n{}", error);
+    }
+}
+
+/// Resolves the string shown for `$subscription`: an alias keyed on either the
+/// subscription id or name takes precedence, falling back to the (optionally
+/// truncated) subscription name.
+fn resolve_subscription_display(
+    config: &AzureConfig,
+    subscription_id: &SubscriptionId,
+    subscription_name: &SubscriptionName,
+) -> String {
+    config
+        .aliases
+        .get(subscription_id)
+        .or_else(|| config.aliases.get(subscription_name))
+        .map(|alias| alias.to_string())
+        .unwrap_or_else(|| {
+            truncate_name(
+                subscription_name,
+                config.truncation_length,
+                config.truncation_symbol,
+            )
+        })
+}
+
+fn truncate_name(name: &str, truncation_length: i64, truncation_symbol: &str) -> String {
+    if truncation_length <= 0 {
+        return name.to_string();
+    }
+
+    let truncation_length = truncation_length as usize;
+    let graphemes: Vec<&str> = name.graphemes(true).collect();
+
+    if graphemes.len() <= truncation_length {
+        name.to_string()
+    } else {
+        format!("{}{}", graphemes[..truncation_length].concat(), truncation_symbol)
     }
 }
 
@@ -97,28 +245,41 @@ fn parse_json(json_file_path: &PathBuf) -> Option<JValue> {
         let mut reader = BufReader::new(json_file);
         reader.read_to_end(&mut buffer).ok()?;
     } else {
-      return None
+        return None;
     }
 
-    let bytes = buffer.as_mut_slice();
-    let decodedbuffer;
+    // `azureProfile.json` is written by the Azure CLI, which on Windows commonly
+    // emits it as UTF-16LE or UTF-8-with-BOM. Detect the BOM (if any) and decode
+    // accordingly, defaulting to UTF-8 when no BOM is present.
+    let (encoding, bom_length) =
+        Encoding::for_bom(&buffer).unwrap_or((encoding_rs::UTF_8, 0));
+    let (decoded, _, _) = encoding.decode(&buffer[bom_length..]);
 
-    if let Some(&[239, 187, 191]) = bytes.get(0..2) {
-        decodedbuffer = bytes.strip_prefix(&[239, 187, 191]).unwrap();
-    } else {
-        decodedbuffer = bytes;
-    }
-
-    let parsed_json: JValue = serde_json::from_slice(&decodedbuffer).ok()?;
-    return Some(parsed_json);
+    let parsed_json: JValue = serde_json::from_str(&decoded).ok()?;
+    Some(parsed_json)
 }
 
-fn find_subscription_name(subscription: &JValue, current_subscription_id: SubscriptionId) -> Option<SubscriptionName> {
+fn find_subscription(
+    subscription: &JValue,
+    current_subscription_id: SubscriptionId,
+) -> Option<SubscriptionInfo> {
     let subscription_id = subscription["id"].as_str()?;
 
     if subscription_id == current_subscription_id {
-        let subscription_name = subscription["name"].as_str()?;
-        return Some(subscription_name.to_string());
+        let name = subscription["name"].as_str()?.to_string();
+        let username = subscription["user"]["name"].as_str().map(str::to_string);
+        let tenant = subscription["homeTenantId"]
+            .as_str()
+            .or_else(|| subscription["tenantId"].as_str())
+            .map(str::to_string);
+        let cloud = subscription["environmentName"].as_str().map(str::to_string);
+
+        return Some(SubscriptionInfo {
+            name,
+            username,
+            tenant,
+            cloud,
+        });
     }
     None
 }
@@ -131,6 +292,7 @@ mod tests {
     use std::fs::File;
     use std::io::{self, Write};
 
+    use filetime::FileTime;
     use tempfile::TempDir;
 
     fn generate_test_config(
@@ -224,6 +386,56 @@ mod tests {
         dir.close()
     }
 
+    #[test]
+    fn identity_details_exposed_as_format_variables() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+
+        let mut clouds_config_ini = Ini::new();
+        clouds_config_ini
+            .with_section(Some("AzureCloud"))
+            .set("subscription", "f3935dc9-92b5-9a93-da7b-42c325d86939");
+
+        let azure_profile_contents = r#"{
+            "installationId": "3deacd2a-b9db-77e1-aa42-23e2f8dfffc3",
+            "subscriptions": [
+              {
+                "id": "f3935dc9-92b5-9a93-da7b-42c325d86939",
+                "name": "Subscription 1",
+                "state": "Enabled",
+                "user": {
+                  "name": "user@domain.com",
+                  "type": "user"
+                },
+                "isDefault": true,
+                "tenantId": "f0273a19-7779-e40a-00a1-53b8331b3bb6",
+                "environmentName": "AzureUSGovernment",
+                "homeTenantId": "f0273a19-7779-e40a-00a1-53b8331b3bb6",
+                "managedByTenants": []
+              }
+            ]
+          }
+        "#;
+
+        generate_test_config(&dir, &clouds_config_ini, azure_profile_contents)?;
+        let dir_path = &dir.path().to_string_lossy();
+        let actual = ModuleRenderer::new("azure")
+            .config(toml::toml! {
+            [azure]
+            disabled = false
+            format = "on [$symbol($subscription:$username@$tenant:$cloud)]($style) "
+            })
+            .env("AZURE_CONFIG_DIR", dir_path.as_ref())
+            .collect();
+        let expected = Some(format!(
+            "on {} ",
+            Color::Blue.bold().paint(
+                "ﴃ Subscription 1:user@domain.com@f0273a19-7779-e40a-00a1-53b8331b3bb6:AzureUSGovernment"
+            )
+        ));
+        assert_eq!(actual, expected);
+        dir.close()
+    }
+
     #[test]
     fn subscription_azure_profile_empty() -> io::Result<()> {
         let dir = tempfile::tempdir()?;
@@ -253,6 +465,231 @@ mod tests {
         dir.close()
     }
 
+    #[test]
+    fn subscription_name_is_aliased() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+
+        let mut clouds_config_ini = Ini::new();
+        clouds_config_ini
+            .with_section(Some("AzureCloud"))
+            .set("subscription", "f3935dc9-92b5-9a93-da7b-42c325d86939");
+
+        let azure_profile_contents = r#"{
+            "installationId": "3deacd2a-b9db-77e1-aa42-23e2f8dfffc3",
+            "subscriptions": [
+              {
+                "id": "f3935dc9-92b5-9a93-da7b-42c325d86939",
+                "name": "Contoso-Production-EastUS-001",
+                "state": "Enabled",
+                "user": {
+                  "name": "user@domain.com",
+                  "type": "user"
+                },
+                "isDefault": true,
+                "tenantId": "f0273a19-7779-e40a-00a1-53b8331b3bb6",
+                "environmentName": "AzureCloud",
+                "homeTenantId": "f0273a19-7779-e40a-00a1-53b8331b3bb6",
+                "managedByTenants": []
+              }
+            ]
+          }
+        "#;
+
+        generate_test_config(&dir, &clouds_config_ini, azure_profile_contents)?;
+        let dir_path = &dir.path().to_string_lossy();
+        let actual = ModuleRenderer::new("azure")
+            .config(toml::toml! {
+                [azure]
+                disabled = false
+                [azure.aliases]
+                "Contoso-Production-EastUS-001" = "prod"
+            })
+            .env("AZURE_CONFIG_DIR", dir_path.as_ref())
+            .collect();
+        let expected = Some(format!("on {} ", Color::Blue.bold().paint("ﴃ prod")));
+        assert_eq!(actual, expected);
+        dir.close()
+    }
+
+    #[test]
+    fn subscription_id_is_aliased_and_takes_precedence_over_truncation() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+
+        let mut clouds_config_ini = Ini::new();
+        clouds_config_ini
+            .with_section(Some("AzureCloud"))
+            .set("subscription", "f3935dc9-92b5-9a93-da7b-42c325d86939");
+
+        let azure_profile_contents = r#"{
+            "installationId": "3deacd2a-b9db-77e1-aa42-23e2f8dfffc3",
+            "subscriptions": [
+              {
+                "id": "f3935dc9-92b5-9a93-da7b-42c325d86939",
+                "name": "Contoso-Production-EastUS-001",
+                "state": "Enabled",
+                "user": {
+                  "name": "user@domain.com",
+                  "type": "user"
+                },
+                "isDefault": true,
+                "tenantId": "f0273a19-7779-e40a-00a1-53b8331b3bb6",
+                "environmentName": "AzureCloud",
+                "homeTenantId": "f0273a19-7779-e40a-00a1-53b8331b3bb6",
+                "managedByTenants": []
+              }
+            ]
+          }
+        "#;
+
+        generate_test_config(&dir, &clouds_config_ini, azure_profile_contents)?;
+        let dir_path = &dir.path().to_string_lossy();
+        let actual = ModuleRenderer::new("azure")
+            .config(toml::toml! {
+                [azure]
+                disabled = false
+                truncation_length = 3
+                [azure.aliases]
+                "f3935dc9-92b5-9a93-da7b-42c325d86939" = "prod"
+            })
+            .env("AZURE_CONFIG_DIR", dir_path.as_ref())
+            .collect();
+        let expected = Some(format!("on {} ", Color::Blue.bold().paint("ﴃ prod")));
+        assert_eq!(actual, expected);
+        dir.close()
+    }
+
+    #[test]
+    fn subscription_name_is_truncated() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+
+        let mut clouds_config_ini = Ini::new();
+        clouds_config_ini
+            .with_section(Some("AzureCloud"))
+            .set("subscription", "f3935dc9-92b5-9a93-da7b-42c325d86939");
+
+        let azure_profile_contents = r#"{
+            "installationId": "3deacd2a-b9db-77e1-aa42-23e2f8dfffc3",
+            "subscriptions": [
+              {
+                "id": "f3935dc9-92b5-9a93-da7b-42c325d86939",
+                "name": "Contoso-Production-EastUS-001",
+                "state": "Enabled",
+                "user": {
+                  "name": "user@domain.com",
+                  "type": "user"
+                },
+                "isDefault": true,
+                "tenantId": "f0273a19-7779-e40a-00a1-53b8331b3bb6",
+                "environmentName": "AzureCloud",
+                "homeTenantId": "f0273a19-7779-e40a-00a1-53b8331b3bb6",
+                "managedByTenants": []
+              }
+            ]
+          }
+        "#;
+
+        generate_test_config(&dir, &clouds_config_ini, azure_profile_contents)?;
+        let dir_path = &dir.path().to_string_lossy();
+        let actual = ModuleRenderer::new("azure")
+            .config(toml::toml! {
+                [azure]
+                disabled = false
+                truncation_length = 7
+                truncation_symbol = "…"
+            })
+            .env("AZURE_CONFIG_DIR", dir_path.as_ref())
+            .collect();
+        let expected = Some(format!("on {} ", Color::Blue.bold().paint("ﴃ Contoso…")));
+        assert_eq!(actual, expected);
+        dir.close()
+    }
+
+    #[test]
+    fn subscription_info_is_cached_between_renders() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+
+        let mut clouds_config_ini = Ini::new();
+        clouds_config_ini
+            .with_section(Some("AzureCloud"))
+            .set("subscription", "f3935dc9-92b5-9a93-da7b-42c325d86939");
+
+        // "Subscription 1" / "Subscription 2" are the same byte length, so
+        // swapping one for the other below leaves the file's (mtime, size)
+        // signature restorable without touching the rest of the payload.
+        let azure_profile_contents = r#"{
+            "installationId": "3deacd2a-b9db-77e1-aa42-23e2f8dfffc3",
+            "subscriptions": [
+              {
+                "id": "f3935dc9-92b5-9a93-da7b-42c325d86939",
+                "name": "Subscription 1",
+                "state": "Enabled",
+                "user": {
+                  "name": "user@domain.com",
+                  "type": "user"
+                },
+                "isDefault": true,
+                "tenantId": "f0273a19-7779-e40a-00a1-53b8331b3bb6",
+                "environmentName": "AzureCloud",
+                "homeTenantId": "f0273a19-7779-e40a-00a1-53b8331b3bb6",
+                "managedByTenants": []
+              }
+            ]
+          }
+        "#;
+        let swapped_profile_contents =
+            azure_profile_contents.replace("Subscription 1", "Subscription 2");
+        assert_eq!(azure_profile_contents.len(), swapped_profile_contents.len());
+
+        generate_test_config(&dir, &clouds_config_ini, azure_profile_contents)?;
+        let dir_path = &dir.path().to_string_lossy();
+
+        let render = || {
+            ModuleRenderer::new("azure")
+                .config(toml::toml! {
+                [azure]
+                disabled = false
+                })
+                .env("AZURE_CONFIG_DIR", dir_path.as_ref())
+                .collect()
+        };
+
+        let expected = Some(format!(
+            "on {} ",
+            Color::Blue.bold().paint("ﴃ Subscription 1")
+        ));
+
+        let first = render();
+        assert_eq!(first, expected);
+
+        let cache_path = dir.path().join("starship_azure_cache.json");
+        assert!(cache_path.exists());
+        let cached: serde_json::Value =
+            serde_json::from_reader(File::open(&cache_path)?).unwrap();
+        assert_eq!(
+            cached["subscription_id"],
+            "f3935dc9-92b5-9a93-da7b-42c325d86939"
+        );
+
+        // Overwrite the profile with a different subscription name, then
+        // restore the original mtime so the (mtime, size) signature the
+        // cache is keyed on is unchanged. A render that actually consults
+        // the cache (rather than re-parsing) must still report the old,
+        // cached name.
+        let profile_path = dir.path().join("azureProfile.json");
+        let original_mtime = FileTime::from_last_modification_time(&std::fs::metadata(&profile_path)?);
+        std::fs::write(&profile_path, swapped_profile_contents.as_bytes())?;
+        filetime::set_file_mtime(&profile_path, original_mtime)
+            .expect("failed to restore azureProfile.json mtime");
+
+        let second = render();
+        assert_eq!(
+            second, expected,
+            "a render with an unchanged (mtime, size) signature must return the cached value, not re-parse the edited file"
+        );
+
+        dir.close()
+    }
+
     #[test]
     fn files_missing() -> io::Result<()> {
         let dir = tempfile::tempdir()?;
@@ -266,4 +703,122 @@ mod tests {
         assert_eq!(actual, expected);
         dir.close()
     }
+
+    fn generate_test_config_bytes(
+        dir: &TempDir,
+        cloud_config_contents: &Ini,
+        azure_profile_bytes: &[u8],
+    ) -> io::Result<()> {
+        let clouds_config_path = dir.path().join("clouds.config");
+        cloud_config_contents.write_to_file(clouds_config_path.as_path())?;
+
+        let azure_profile_path = dir.path().join("azureProfile.json");
+        let mut azure_profile_file = File::create(&azure_profile_path)?;
+        azure_profile_file.write_all(azure_profile_bytes)?;
+
+        azure_profile_file.sync_all()?;
+        Ok(())
+    }
+
+    #[test]
+    fn subscription_set_correctly_with_utf8_bom() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+
+        let mut clouds_config_ini = Ini::new();
+        clouds_config_ini
+            .with_section(Some("AzureCloud"))
+            .set("subscription", "f3935dc9-92b5-9a93-da7b-42c325d86939");
+
+        let azure_profile_contents = r#"{
+            "installationId": "3deacd2a-b9db-77e1-aa42-23e2f8dfffc3",
+            "subscriptions": [
+              {
+                "id": "f3935dc9-92b5-9a93-da7b-42c325d86939",
+                "name": "Subscription 1",
+                "state": "Enabled",
+                "user": {
+                  "name": "user@domain.com",
+                  "type": "user"
+                },
+                "isDefault": true,
+                "tenantId": "f0273a19-7779-e40a-00a1-53b8331b3bb6",
+                "environmentName": "AzureCloud",
+                "homeTenantId": "f0273a19-7779-e40a-00a1-53b8331b3bb6",
+                "managedByTenants": []
+              }
+            ]
+          }
+        "#;
+
+        let mut azure_profile_bytes = vec![0xEF, 0xBB, 0xBF];
+        azure_profile_bytes.extend_from_slice(azure_profile_contents.as_bytes());
+
+        generate_test_config_bytes(&dir, &clouds_config_ini, &azure_profile_bytes)?;
+        let dir_path = &dir.path().to_string_lossy();
+        let actual = ModuleRenderer::new("azure")
+            .config(toml::toml! {
+            [azure]
+            disabled = false
+            })
+            .env("AZURE_CONFIG_DIR", dir_path.as_ref())
+            .collect();
+        let expected = Some(format!(
+            "on {} ",
+            Color::Blue.bold().paint("ﴃ Subscription 1")
+        ));
+        assert_eq!(actual, expected);
+        dir.close()
+    }
+
+    #[test]
+    fn subscription_set_correctly_with_utf16le() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+
+        let mut clouds_config_ini = Ini::new();
+        clouds_config_ini
+            .with_section(Some("AzureCloud"))
+            .set("subscription", "f3935dc9-92b5-9a93-da7b-42c325d86939");
+
+        let azure_profile_contents = r#"{
+            "installationId": "3deacd2a-b9db-77e1-aa42-23e2f8dfffc3",
+            "subscriptions": [
+              {
+                "id": "f3935dc9-92b5-9a93-da7b-42c325d86939",
+                "name": "Subscription 1",
+                "state": "Enabled",
+                "user": {
+                  "name": "user@domain.com",
+                  "type": "user"
+                },
+                "isDefault": true,
+                "tenantId": "f0273a19-7779-e40a-00a1-53b8331b3bb6",
+                "environmentName": "AzureCloud",
+                "homeTenantId": "f0273a19-7779-e40a-00a1-53b8331b3bb6",
+                "managedByTenants": []
+              }
+            ]
+          }
+        "#;
+
+        let mut azure_profile_bytes = vec![0xFF, 0xFE];
+        for unit in azure_profile_contents.encode_utf16() {
+            azure_profile_bytes.extend_from_slice(&unit.to_le_bytes());
+        }
+
+        generate_test_config_bytes(&dir, &clouds_config_ini, &azure_profile_bytes)?;
+        let dir_path = &dir.path().to_string_lossy();
+        let actual = ModuleRenderer::new("azure")
+            .config(toml::toml! {
+            [azure]
+            disabled = false
+            })
+            .env("AZURE_CONFIG_DIR", dir_path.as_ref())
+            .collect();
+        let expected = Some(format!(
+            "on {} ",
+            Color::Blue.bold().paint("ﴃ Subscription 1")
+        ));
+        assert_eq!(actual, expected);
+        dir.close()
+    }
 }